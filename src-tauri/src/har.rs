@@ -1,3 +1,6 @@
+use chrono::{NaiveDate, NaiveDateTime};
+use percent_encoding::percent_decode_str;
+use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 use url::Url;
@@ -7,6 +10,9 @@ pub struct HarRequest {
     pub method: String,
     pub url: String,
     pub path: String,
+    // Normalized form of `path` used for matching/alignment; `path` itself is
+    // kept verbatim for display so users still see what was actually sent.
+    pub normalized_path: String,
     pub headers: HashMap<String, String>,
     pub query_params: HashMap<String, Vec<String>>,
     pub post_data: Option<String>,
@@ -16,6 +22,85 @@ pub struct HarRequest {
     pub index: usize,
 }
 
+// Controls how request paths/URLs are canonicalized before being used for
+// alignment and comparison, so incidental differences (trailing slashes,
+// encoding, query param order) don't register as distinct endpoints.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NormalizationOptions {
+    pub strip_trailing_slash: bool,
+    pub treat_empty_query_as_absent: bool,
+    pub canonicalize_percent_encoding: bool,
+    pub sort_query_params: bool,
+}
+
+impl Default for NormalizationOptions {
+    fn default() -> Self {
+        NormalizationOptions {
+            strip_trailing_slash: true,
+            treat_empty_query_as_absent: true,
+            canonicalize_percent_encoding: true,
+            sort_query_params: true,
+        }
+    }
+}
+
+// Builds the normalized path+query used for comparison/alignment. Scheme and
+// host casing/percent-encoding are already canonicalized by `Url::parse`, so
+// this only needs to handle the path and query string.
+pub fn normalize_path(url: &str, options: &NormalizationOptions) -> String {
+    let parsed = match Url::parse(url) {
+        Ok(parsed) => parsed,
+        Err(_) => return url.to_string(),
+    };
+
+    let mut path = if options.canonicalize_percent_encoding {
+        percent_decode_str(parsed.path())
+            .decode_utf8_lossy()
+            .to_string()
+    } else {
+        parsed.path().to_string()
+    };
+
+    if options.strip_trailing_slash && path.len() > 1 && path.ends_with('/') {
+        path.pop();
+    }
+
+    let normalized_query = match parsed.query() {
+        None => None,
+        Some(query) => {
+            let mut pairs: Vec<(String, String)> = url::form_urlencoded::parse(query.as_bytes())
+                .into_owned()
+                .collect();
+
+            if pairs.is_empty() && options.treat_empty_query_as_absent {
+                None
+            } else {
+                if options.sort_query_params {
+                    pairs.sort_by(|a, b| a.0.cmp(&b.0));
+                }
+                Some(
+                    pairs
+                        .into_iter()
+                        .map(|(k, v)| format!("{}={}", k, v))
+                        .collect::<Vec<_>>()
+                        .join("&"),
+                )
+            }
+        }
+    };
+
+    match normalized_query {
+        Some(q) => format!("{}?{}", path, q),
+        None => path,
+    }
+}
+
+// Recomputes `normalized_path` from `req.url` under a different set of
+// normalization rules, without touching the raw `path` used for display.
+pub fn renormalize_request(req: &mut HarRequest, options: &NormalizationOptions) {
+    req.normalized_path = normalize_path(&req.url, options);
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct HarFile {
     pub requests: Vec<HarRequest>,
@@ -54,6 +139,28 @@ pub struct LocalWhitelistRule {
     pub url: Option<String>,
     pub headers: Option<Vec<String>>,
     pub payload_keys: Option<Vec<String>>,
+    // How `headers`/`payload_keys` should be interpreted for this rule.
+    // Absent in existing configs, so it defaults to `Deny` to preserve
+    // the original "these fields are allowed to differ" (masked) behavior.
+    #[serde(default)]
+    pub mode: WhitelistMode,
+}
+
+// Whether a rule's field lists are an allow-list (only the listed fields are
+// compared, everything else for that host is ignored) or a deny-list (the
+// listed fields are masked out/ignored, everything else is compared - the
+// original whitelist behavior).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WhitelistMode {
+    Allow,
+    Deny,
+}
+
+impl Default for WhitelistMode {
+    fn default() -> Self {
+        WhitelistMode::Deny
+    }
 }
 
 impl WhitelistConfig {
@@ -70,9 +177,25 @@ impl WhitelistConfig {
         if let Some(local_rules) = &self.local {
             for rule in local_rules {
                 if self.rule_matches_url(rule, url) {
-                    if let Some(headers) = &rule.headers {
-                        if headers.iter().any(|h| h.eq_ignore_ascii_case(header_name)) {
-                            return true;
+                    let listed = rule
+                        .headers
+                        .as_ref()
+                        .map(|headers| headers.iter().any(|h| h.eq_ignore_ascii_case(header_name)))
+                        .unwrap_or(false);
+                    match rule.mode {
+                        // Allow-list: only listed fields are compared, so
+                        // everything NOT listed is ignored.
+                        WhitelistMode::Allow => {
+                            if !listed {
+                                return true;
+                            }
+                        }
+                        // Deny-list: listed fields are masked out, everything
+                        // else is compared.
+                        WhitelistMode::Deny => {
+                            if listed {
+                                return true;
+                            }
                         }
                     }
                 }
@@ -97,9 +220,25 @@ impl WhitelistConfig {
         if let Some(local_rules) = &self.local {
             for rule in local_rules {
                 if self.rule_matches_url(rule, url) {
-                    if let Some(payload_keys) = &rule.payload_keys {
-                        if payload_keys.contains(&key_name.to_string()) {
-                            return true;
+                    let listed = rule
+                        .payload_keys
+                        .as_ref()
+                        .map(|payload_keys| payload_keys.contains(&key_name.to_string()))
+                        .unwrap_or(false);
+                    match rule.mode {
+                        // Allow-list: only listed fields are compared, so
+                        // everything NOT listed is ignored.
+                        WhitelistMode::Allow => {
+                            if !listed {
+                                return true;
+                            }
+                        }
+                        // Deny-list: listed fields are masked out, everything
+                        // else is compared.
+                        WhitelistMode::Deny => {
+                            if listed {
+                                return true;
+                            }
                         }
                     }
                 }
@@ -141,6 +280,109 @@ impl WhitelistConfig {
     }
 }
 
+// Pact-style matching rules: lets callers declare that a field should be
+// compared against a shape (type/regex/timestamp/...) instead of requiring
+// byte-for-byte equality, for values that legitimately differ per-request
+// (ids, nonces, timestamps) but must still conform to a contract.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MatchingConfig {
+    pub rules: Vec<MatchingRule>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MatchingRule {
+    // A dot path such as "headers.Date", "query.token" or "body.order.id".
+    // A leading "$." (as in Pact path expressions) is tolerated and stripped.
+    pub path: String,
+    pub matcher: MatcherKind,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum MatcherKind {
+    Equality,
+    Type,
+    Regex { pattern: String },
+    Include,
+    Number,
+    Integer,
+    Decimal,
+    Timestamp { format: String },
+}
+
+impl MatchingConfig {
+    pub fn new() -> Self {
+        MatchingConfig { rules: Vec::new() }
+    }
+
+    fn matcher_for(&self, path: &str) -> Option<&MatcherKind> {
+        self.rules
+            .iter()
+            .find(|rule| Self::paths_match(Self::normalize_path(&rule.path), path))
+            .map(|rule| &rule.matcher)
+    }
+
+    fn normalize_path(expr: &str) -> &str {
+        expr.strip_prefix("$.").unwrap_or(expr)
+    }
+
+    // Header names are matched case-insensitively, mirroring
+    // `WhitelistConfig::is_header_whitelisted`, so a rule declared against
+    // `$.headers.Date` still fires when the capture recorded `date`/`DATE`.
+    // Every other path segment (query, body, ...) stays an exact match.
+    fn paths_match(rule_path: &str, query_path: &str) -> bool {
+        match (rule_path.strip_prefix("headers."), query_path.strip_prefix("headers.")) {
+            (Some(rule_header), Some(query_header)) => rule_header.eq_ignore_ascii_case(query_header),
+            _ => rule_path == query_path,
+        }
+    }
+}
+
+// Returns true when `val1`/`val2` satisfy `kind`, i.e. the difference between
+// them (if any) is acceptable under the declared matcher.
+fn values_satisfy_matcher(
+    kind: &MatcherKind,
+    val1: &serde_json::Value,
+    val2: &serde_json::Value,
+) -> bool {
+    match kind {
+        MatcherKind::Equality => val1 == val2,
+        MatcherKind::Type => std::mem::discriminant(val1) == std::mem::discriminant(val2),
+        MatcherKind::Regex { pattern } => match Regex::new(pattern) {
+            Ok(re) => match (scalar_as_str(val1), scalar_as_str(val2)) {
+                (Some(s1), Some(s2)) => re.is_match(&s1) && re.is_match(&s2),
+                _ => false,
+            },
+            Err(_) => false,
+        },
+        MatcherKind::Include => match (scalar_as_str(val1), scalar_as_str(val2)) {
+            (Some(s1), Some(s2)) => s1.contains(s2.as_str()) || s2.contains(s1.as_str()),
+            _ => false,
+        },
+        MatcherKind::Number => val1.is_number() && val2.is_number(),
+        MatcherKind::Integer => val1.is_i64() && val2.is_i64(),
+        MatcherKind::Decimal => val1.is_f64() && val2.is_f64(),
+        MatcherKind::Timestamp { format } => match (scalar_as_str(val1), scalar_as_str(val2)) {
+            (Some(s1), Some(s2)) => parses_as_timestamp(&s1, format) && parses_as_timestamp(&s2, format),
+            _ => false,
+        },
+    }
+}
+
+fn scalar_as_str(value: &serde_json::Value) -> Option<String> {
+    match value {
+        serde_json::Value::String(s) => Some(s.clone()),
+        serde_json::Value::Number(n) => Some(n.to_string()),
+        serde_json::Value::Bool(b) => Some(b.to_string()),
+        _ => None,
+    }
+}
+
+fn parses_as_timestamp(value: &str, format: &str) -> bool {
+    NaiveDateTime::parse_from_str(value, format).is_ok()
+        || NaiveDate::parse_from_str(value, format).is_ok()
+}
+
 // Raw HAR format structures for parsing
 #[derive(Debug, Deserialize)]
 struct RawHar {
@@ -199,7 +441,7 @@ struct RawContent {
 }
 
 impl HarRequest {
-    fn from_raw_entry(entry: &RawEntry, index: usize) -> Result<Self, Box<dyn std::error::Error>> {
+    fn from_raw_entry(entry: &RawEntry, index: usize, options: &NormalizationOptions) -> Result<Self, Box<dyn std::error::Error>> {
         let request = &entry.request;
         let response = &entry.response;
 
@@ -212,6 +454,8 @@ impl HarRequest {
             path.push_str(query);
         }
 
+        let normalized_path = normalize_path(url, options);
+
         // Convert headers to HashMap
         let mut headers = HashMap::new();
         for header in &request.headers {
@@ -245,6 +489,7 @@ impl HarRequest {
             method: request.method.clone(),
             url: url.clone(),
             path,
+            normalized_path,
             headers,
             query_params,
             post_data,
@@ -256,18 +501,45 @@ impl HarRequest {
     }
 }
 
+// Callbacks used by long-running parse/align operations to report progress
+// and allow cooperative cancellation, without coupling this module to Tauri.
+pub type ProgressCallback<'a> = dyn FnMut(usize, usize) + 'a;
+pub type CancelCheck<'a> = dyn Fn() -> bool + 'a;
+
 pub fn parse_har_file(content: &str) -> Result<Vec<HarRequest>, Box<dyn std::error::Error>> {
+    parse_har_file_with_progress(content, None, None)
+}
+
+pub fn parse_har_file_with_progress(
+    content: &str,
+    options: Option<&NormalizationOptions>,
+    mut on_progress: Option<&mut ProgressCallback>,
+) -> Result<Vec<HarRequest>, Box<dyn std::error::Error>> {
+    let default_options = NormalizationOptions::default();
+    let options = options.unwrap_or(&default_options);
+
     let raw_har: RawHar = serde_json::from_str(content)?;
     let mut requests = Vec::new();
+    let total = raw_har.log.entries.len();
+
+    // Emit progress at most every PROGRESS_STEP entries so large captures
+    // don't flood the UI with events.
+    const PROGRESS_STEP: usize = 200;
 
     for (index, entry) in raw_har.log.entries.iter().enumerate() {
-        match HarRequest::from_raw_entry(entry, index + 1) { // Start index from 1
+        match HarRequest::from_raw_entry(entry, index + 1, options) { // Start index from 1
             Ok(request) => requests.push(request),
             Err(e) => {
                 eprintln!("Warning: Failed to parse entry {}: {}", index + 1, e);
                 // Continue parsing other entries
             }
         }
+
+        if let Some(cb) = on_progress.as_deref_mut() {
+            if (index + 1) % PROGRESS_STEP == 0 || index + 1 == total {
+                cb(index + 1, total);
+            }
+        }
     }
 
     Ok(requests)
@@ -278,8 +550,18 @@ pub fn parse_whitelist_config(content: &str) -> Result<WhitelistConfig, Box<dyn
     Ok(config)
 }
 
+pub fn parse_normalization_config(content: &str) -> Result<NormalizationOptions, Box<dyn std::error::Error>> {
+    let config: NormalizationOptions = serde_json::from_str(content)?;
+    Ok(config)
+}
+
+pub fn parse_matching_config(content: &str) -> Result<MatchingConfig, Box<dyn std::error::Error>> {
+    let config: MatchingConfig = serde_json::from_str(content)?;
+    Ok(config)
+}
+
 pub fn compare_requests(req1: &HarRequest, req2: &HarRequest, keys_only: bool) -> ComparisonResult {
-    compare_requests_with_whitelist(req1, req2, keys_only, &WhitelistConfig::new())
+    compare_requests_with_whitelist(req1, req2, keys_only, &WhitelistConfig::new(), None)
 }
 
 pub fn compare_requests_with_whitelist(
@@ -287,19 +569,20 @@ pub fn compare_requests_with_whitelist(
     req2: &HarRequest,
     _keys_only: bool,
     whitelist: &WhitelistConfig,
+    matching: Option<&MatchingConfig>,
 ) -> ComparisonResult {
     // For GET requests, compare only the path without query parameters
     // For other methods, compare the full path
     let path1 = if req1.method.to_uppercase() == "GET" {
-        req1.path.split('?').next().unwrap_or(&req1.path)
+        req1.normalized_path.split('?').next().unwrap_or(&req1.normalized_path)
     } else {
-        &req1.path
+        &req1.normalized_path
     };
 
     let path2 = if req2.method.to_uppercase() == "GET" {
-        req2.path.split('?').next().unwrap_or(&req2.path)
+        req2.normalized_path.split('?').next().unwrap_or(&req2.normalized_path)
     } else {
-        &req2.path
+        &req2.normalized_path
     };
 
     if path1 != path2 {
@@ -314,7 +597,7 @@ pub fn compare_requests_with_whitelist(
     let mut has_whitelisted_diff = false;
 
     // Compare headers
-    let headers_diff = compare_headers_with_whitelist(&req1.headers, &req2.headers, &req1.url, whitelist);
+    let headers_diff = compare_headers_with_whitelist(&req1.headers, &req2.headers, &req1.url, whitelist, matching);
     if headers_diff.has_non_whitelisted_diff {
         has_non_whitelisted_diff = true;
     }
@@ -324,14 +607,15 @@ pub fn compare_requests_with_whitelist(
 
     // Compare query params (skip for GET requests)
     if req1.method.to_uppercase() != "GET" {
-        if req1.query_params != req2.query_params {
+        if compare_query_params_with_matching(&req1.query_params, &req2.query_params, matching) {
             has_non_whitelisted_diff = true;
         }
     }
 
     // Compare post data with whitelist consideration
     if let (Some(data1), Some(data2)) = (&req1.post_data, &req2.post_data) {
-        let payload_diff = compare_payload_with_whitelist(data1, data2, &req1.url, whitelist);
+        let content_type = header_value(&req1.headers, "Content-Type");
+        let payload_diff = compare_payload_with_whitelist(data1, data2, &req1.url, whitelist, matching, content_type);
         if payload_diff.has_non_whitelisted_diff {
             has_non_whitelisted_diff = true;
         }
@@ -374,6 +658,29 @@ pub fn compare_requests_with_whitelist(
 struct DiffResult {
     has_non_whitelisted_diff: bool,
     has_whitelisted_diff: bool,
+    entries: Vec<DiffEntry>,
+}
+
+// A single flat, key-addressable difference, keyed by dot path (e.g.
+// "headers.Date" or "body.order.id") rather than by rendered line number.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiffEntry {
+    pub path: String,
+    pub left_value: Option<serde_json::Value>,
+    pub right_value: Option<serde_json::Value>,
+    pub kind: String, // "changed", "added", "removed", or "whitelisted"
+}
+
+fn diff_kind(whitelisted: bool, present_left: bool, present_right: bool) -> &'static str {
+    if whitelisted {
+        "whitelisted"
+    } else if !present_left {
+        "added"
+    } else if !present_right {
+        "removed"
+    } else {
+        "changed"
+    }
 }
 
 fn compare_headers_with_whitelist(
@@ -381,10 +688,12 @@ fn compare_headers_with_whitelist(
     headers2: &HashMap<String, String>,
     url: &str,
     whitelist: &WhitelistConfig,
+    matching: Option<&MatchingConfig>,
 ) -> DiffResult {
     let mut result = DiffResult {
         has_non_whitelisted_diff: false,
         has_whitelisted_diff: false,
+        entries: Vec::new(),
     };
 
     let all_keys: HashSet<&String> = headers1.keys().chain(headers2.keys()).collect();
@@ -394,49 +703,461 @@ fn compare_headers_with_whitelist(
         let val2 = headers2.get(key);
 
         if val1 != val2 {
-            if whitelist.is_header_whitelisted(key, url) {
+            if let (Some(v1), Some(v2)) = (val1, val2) {
+                if matcher_matches(matching, &format!("headers.{}", key), &json_str(v1), &json_str(v2)) {
+                    continue;
+                }
+            }
+
+            let whitelisted = whitelist.is_header_whitelisted(key, url);
+            if whitelisted {
                 result.has_whitelisted_diff = true;
             } else {
                 result.has_non_whitelisted_diff = true;
             }
+
+            result.entries.push(DiffEntry {
+                path: format!("headers.{}", key),
+                left_value: val1.map(|v| serde_json::Value::String(v.clone())),
+                right_value: val2.map(|v| serde_json::Value::String(v.clone())),
+                kind: diff_kind(whitelisted, val1.is_some(), val2.is_some()).to_string(),
+            });
         }
     }
 
     result
 }
 
+// Returns true (i.e. "not a diff") when a matching rule is declared for `path`
+// and both values satisfy it.
+fn matcher_matches(
+    matching: Option<&MatchingConfig>,
+    path: &str,
+    val1: &serde_json::Value,
+    val2: &serde_json::Value,
+) -> bool {
+    matching
+        .and_then(|m| m.matcher_for(path))
+        .is_some_and(|kind| values_satisfy_matcher(kind, val1, val2))
+}
+
+fn json_str(value: &str) -> serde_json::Value {
+    serde_json::Value::String(value.to_string())
+}
+
+fn compare_query_params_with_matching(
+    params1: &HashMap<String, Vec<String>>,
+    params2: &HashMap<String, Vec<String>>,
+    matching: Option<&MatchingConfig>,
+) -> bool {
+    let all_keys: HashSet<&String> = params1.keys().chain(params2.keys()).collect();
+
+    for key in all_keys {
+        let v1 = params1.get(key);
+        let v2 = params2.get(key);
+
+        if v1 != v2 {
+            let matched = match (v1, v2) {
+                (Some(a), Some(b)) if a.len() == 1 && b.len() == 1 => matcher_matches(
+                    matching,
+                    &format!("query.{}", key),
+                    &json_str(&a[0]),
+                    &json_str(&b[0]),
+                ),
+                _ => false,
+            };
+
+            if !matched {
+                return true; // non-whitelisted, non-matched diff
+            }
+        }
+    }
+
+    false
+}
+
+// A parsed `Content-Type` header: the bare MIME type plus any parameters
+// (`charset`, `boundary`, ...), tolerant of casing and quoted values.
+struct ContentType {
+    mime_type: String,
+    parameters: HashMap<String, String>,
+}
+
+impl ContentType {
+    fn parse(value: &str) -> Self {
+        let mut parts = value.split(';');
+        let mime_type = parts.next().unwrap_or("").trim().to_lowercase();
+        let mut parameters = HashMap::new();
+
+        for part in parts {
+            if let Some((key, val)) = part.split_once('=') {
+                parameters.insert(
+                    key.trim().to_lowercase(),
+                    val.trim().trim_matches('"').to_string(),
+                );
+            }
+        }
+
+        ContentType { mime_type, parameters }
+    }
+}
+
+fn header_value<'a>(headers: &'a HashMap<String, String>, name: &str) -> Option<&'a str> {
+    headers
+        .iter()
+        .find(|(key, _)| key.eq_ignore_ascii_case(name))
+        .map(|(_, value)| value.as_str())
+}
+
 fn compare_payload_with_whitelist(
     payload1: &str,
     payload2: &str,
     url: &str,
     whitelist: &WhitelistConfig,
+    matching: Option<&MatchingConfig>,
+    content_type: Option<&str>,
 ) -> DiffResult {
     let mut result = DiffResult {
         has_non_whitelisted_diff: false,
         has_whitelisted_diff: false,
+        entries: Vec::new(),
     };
 
-    // Try to parse as JSON and compare keys
-    if let (Ok(json1), Ok(json2)) = (
-        serde_json::from_str::<serde_json::Value>(payload1),
-        serde_json::from_str::<serde_json::Value>(payload2),
-    ) {
-        compare_json_values(&json1, &json2, url, whitelist, &mut result, "");
-    } else {
-        // If not JSON, do simple string comparison
-        if payload1 != payload2 {
-            result.has_non_whitelisted_diff = true;
+    let content_type = content_type.map(ContentType::parse);
+
+    match content_type.as_ref().map(|ct| ct.mime_type.as_str()) {
+        Some("application/x-www-form-urlencoded") => {
+            let params1 = parse_form_urlencoded(payload1);
+            let params2 = parse_form_urlencoded(payload2);
+            compare_keyed_values_with_whitelist(&params1, &params2, url, whitelist, matching, &mut result, "body");
+        }
+        Some(mime) if mime.starts_with("multipart/") => {
+            match content_type.as_ref().and_then(|ct| ct.parameters.get("boundary")) {
+                Some(boundary) => {
+                    let parts1 = parse_multipart(payload1, boundary);
+                    let parts2 = parse_multipart(payload2, boundary);
+                    compare_keyed_values_with_whitelist(&parts1, &parts2, url, whitelist, matching, &mut result, "body");
+                }
+                None => {
+                    if payload1 != payload2 {
+                        result.has_non_whitelisted_diff = true;
+                    }
+                }
+            }
+        }
+        Some(mime) if mime.ends_with("/xml") || mime.ends_with("+xml") => {
+            compare_xml_with_whitelist(payload1, payload2, url, whitelist, matching, &mut result);
+        }
+        _ => {
+            // Unknown/absent content type: try JSON, then fall back to a raw string compare.
+            if let (Ok(json1), Ok(json2)) = (
+                serde_json::from_str::<serde_json::Value>(payload1),
+                serde_json::from_str::<serde_json::Value>(payload2),
+            ) {
+                compare_json_values(&json1, &json2, url, whitelist, matching, &mut result, "body");
+            } else if payload1 != payload2 {
+                result.has_non_whitelisted_diff = true;
+            }
         }
     }
 
     result
 }
 
+fn parse_form_urlencoded(body: &str) -> HashMap<String, Vec<String>> {
+    let mut params: HashMap<String, Vec<String>> = HashMap::new();
+    for (key, value) in url::form_urlencoded::parse(body.as_bytes()) {
+        params.entry(key.into_owned()).or_default().push(value.into_owned());
+    }
+    params
+}
+
+fn parse_multipart(body: &str, boundary: &str) -> HashMap<String, Vec<String>> {
+    let delimiter = format!("--{}", boundary);
+    let mut parts: HashMap<String, Vec<String>> = HashMap::new();
+
+    for raw_part in body.split(&delimiter) {
+        let raw_part = raw_part.trim();
+        if raw_part.is_empty() || raw_part == "--" {
+            continue;
+        }
+
+        let Some((headers, part_body)) = raw_part
+            .split_once("\r\n\r\n")
+            .or_else(|| raw_part.split_once("\n\n"))
+        else {
+            continue;
+        };
+
+        let name = headers
+            .lines()
+            .find(|line| line.to_lowercase().starts_with("content-disposition"))
+            .and_then(|line| {
+                line.split(';').find_map(|segment| {
+                    segment.trim().strip_prefix("name=").map(|n| n.trim_matches('"').to_string())
+                })
+            });
+
+        if let Some(name) = name {
+            parts.entry(name).or_default().push(part_body.trim().to_string());
+        }
+    }
+
+    parts
+}
+
+// Shared key-keyed diff used for form fields and multipart parts, mirroring
+// the whitelist/matcher semantics `compare_json_values` applies to objects.
+fn compare_keyed_values_with_whitelist(
+    map1: &HashMap<String, Vec<String>>,
+    map2: &HashMap<String, Vec<String>>,
+    url: &str,
+    whitelist: &WhitelistConfig,
+    matching: Option<&MatchingConfig>,
+    result: &mut DiffResult,
+    path_prefix: &str,
+) {
+    let all_keys: HashSet<&String> = map1.keys().chain(map2.keys()).collect();
+
+    for key in all_keys {
+        let v1 = map1.get(key);
+        let v2 = map2.get(key);
+
+        if v1 != v2 {
+            let matched = match (v1, v2) {
+                (Some(a), Some(b)) if a.len() == 1 && b.len() == 1 => matcher_matches(
+                    matching,
+                    &format!("{}.{}", path_prefix, key),
+                    &json_str(&a[0]),
+                    &json_str(&b[0]),
+                ),
+                _ => false,
+            };
+
+            if matched {
+                continue;
+            }
+
+            let whitelisted = whitelist.is_payload_key_whitelisted(key, url);
+            if whitelisted {
+                result.has_whitelisted_diff = true;
+            } else {
+                result.has_non_whitelisted_diff = true;
+            }
+
+            result.entries.push(DiffEntry {
+                path: format!("{}.{}", path_prefix, key),
+                left_value: v1.map(|values| serde_json::Value::Array(values.iter().map(|v| serde_json::Value::String(v.clone())).collect())),
+                right_value: v2.map(|values| serde_json::Value::Array(values.iter().map(|v| serde_json::Value::String(v.clone())).collect())),
+                kind: diff_kind(whitelisted, v1.is_some(), v2.is_some()).to_string(),
+            });
+        }
+    }
+}
+
+// Minimal XML element tree: just enough structure (tag, attributes, text,
+// children) to diff two documents the same way JSON objects are diffed.
+#[derive(Debug, Clone, PartialEq)]
+struct XmlNode {
+    tag: String,
+    attributes: HashMap<String, String>,
+    text: String,
+    children: Vec<XmlNode>,
+}
+
+fn parse_xml(input: &str) -> Option<XmlNode> {
+    let token_re = Regex::new(r"(?s)<(/?)([A-Za-z_][\w:.-]*)([^>]*?)(/?)>|([^<]+)").unwrap();
+    let attr_re = Regex::new(r#"([A-Za-z_][\w:.-]*)\s*=\s*"([^"]*)""#).unwrap();
+
+    let mut stack: Vec<XmlNode> = Vec::new();
+    let mut root: Option<XmlNode> = None;
+
+    for cap in token_re.captures_iter(input) {
+        if let Some(text) = cap.get(5) {
+            let text = text.as_str().trim();
+            if !text.is_empty() {
+                if let Some(top) = stack.last_mut() {
+                    if !top.text.is_empty() {
+                        top.text.push(' ');
+                    }
+                    top.text.push_str(text);
+                }
+            }
+            continue;
+        }
+
+        let closing = cap.get(1).is_some_and(|m| m.as_str() == "/");
+        let tag = cap.get(2).unwrap().as_str().to_string();
+        let attrs_str = cap.get(3).map_or("", |m| m.as_str());
+        let self_closing = cap.get(4).is_some_and(|m| m.as_str() == "/");
+
+        if closing {
+            if let Some(node) = stack.pop() {
+                match stack.last_mut() {
+                    Some(parent) => parent.children.push(node),
+                    None => root = Some(node),
+                }
+            }
+            continue;
+        }
+
+        let mut attributes = HashMap::new();
+        for attr in attr_re.captures_iter(attrs_str) {
+            attributes.insert(attr[1].to_string(), attr[2].to_string());
+        }
+
+        let node = XmlNode {
+            tag,
+            attributes,
+            text: String::new(),
+            children: Vec::new(),
+        };
+
+        if self_closing {
+            match stack.last_mut() {
+                Some(parent) => parent.children.push(node),
+                None => root = Some(node),
+            }
+        } else {
+            stack.push(node);
+        }
+    }
+
+    // Unwind any still-open elements so malformed XML still yields a tree.
+    while let Some(node) = stack.pop() {
+        match stack.last_mut() {
+            Some(parent) => parent.children.push(node),
+            None => root = Some(node),
+        }
+    }
+
+    root
+}
+
+fn compare_xml_with_whitelist(
+    payload1: &str,
+    payload2: &str,
+    url: &str,
+    whitelist: &WhitelistConfig,
+    matching: Option<&MatchingConfig>,
+    result: &mut DiffResult,
+) {
+    match (parse_xml(payload1), parse_xml(payload2)) {
+        (Some(root1), Some(root2)) => {
+            if root1.tag != root2.tag {
+                result.has_non_whitelisted_diff = true;
+            } else {
+                let path = root1.tag.clone();
+                compare_xml_nodes(&root1, &root2, url, whitelist, matching, result, &path);
+            }
+        }
+        _ => {
+            if payload1 != payload2 {
+                result.has_non_whitelisted_diff = true;
+            }
+        }
+    }
+}
+
+fn compare_xml_nodes(
+    node1: &XmlNode,
+    node2: &XmlNode,
+    url: &str,
+    whitelist: &WhitelistConfig,
+    matching: Option<&MatchingConfig>,
+    result: &mut DiffResult,
+    path: &str,
+) {
+    let all_attrs: HashSet<&String> = node1.attributes.keys().chain(node2.attributes.keys()).collect();
+    for key in all_attrs {
+        let v1 = node1.attributes.get(key);
+        let v2 = node2.attributes.get(key);
+
+        if v1 != v2 {
+            let matched = match (v1, v2) {
+                (Some(a), Some(b)) => matcher_matches(matching, &format!("{}.@{}", path, key), &json_str(a), &json_str(b)),
+                _ => false,
+            };
+
+            if matched {
+                continue;
+            }
+
+            let whitelisted = whitelist.is_payload_key_whitelisted(key, url);
+            if whitelisted {
+                result.has_whitelisted_diff = true;
+            } else {
+                result.has_non_whitelisted_diff = true;
+            }
+
+            result.entries.push(DiffEntry {
+                path: format!("{}.@{}", path, key),
+                left_value: v1.map(|v| serde_json::Value::String(v.clone())),
+                right_value: v2.map(|v| serde_json::Value::String(v.clone())),
+                kind: diff_kind(whitelisted, v1.is_some(), v2.is_some()).to_string(),
+            });
+        }
+    }
+
+    if node1.text != node2.text {
+        let whitelisted = whitelist.is_payload_key_whitelisted(&node1.tag, url);
+        let matcher_ok = matcher_matches(matching, &format!("{}.#text", path), &json_str(&node1.text), &json_str(&node2.text));
+
+        if !matcher_ok {
+            if whitelisted {
+                result.has_whitelisted_diff = true;
+            } else {
+                result.has_non_whitelisted_diff = true;
+            }
+
+            result.entries.push(DiffEntry {
+                path: format!("{}.#text", path),
+                left_value: Some(serde_json::Value::String(node1.text.clone())),
+                right_value: Some(serde_json::Value::String(node2.text.clone())),
+                kind: diff_kind(whitelisted, true, true).to_string(),
+            });
+        }
+    }
+
+    let mut grouped1: HashMap<&str, Vec<&XmlNode>> = HashMap::new();
+    for child in &node1.children {
+        grouped1.entry(child.tag.as_str()).or_default().push(child);
+    }
+    let mut grouped2: HashMap<&str, Vec<&XmlNode>> = HashMap::new();
+    for child in &node2.children {
+        grouped2.entry(child.tag.as_str()).or_default().push(child);
+    }
+
+    let all_tags: HashSet<&str> = grouped1.keys().chain(grouped2.keys()).copied().collect();
+    for tag in all_tags {
+        let empty: Vec<&XmlNode> = Vec::new();
+        let children1 = grouped1.get(tag).unwrap_or(&empty);
+        let children2 = grouped2.get(tag).unwrap_or(&empty);
+        let current_path = format!("{}.{}", path, tag);
+
+        if whitelist.is_payload_key_whitelisted(tag, url) {
+            if children1 != children2 {
+                result.has_whitelisted_diff = true;
+            }
+            continue;
+        }
+
+        if children1.len() != children2.len() {
+            result.has_non_whitelisted_diff = true;
+        }
+
+        for (c1, c2) in children1.iter().zip(children2.iter()) {
+            compare_xml_nodes(c1, c2, url, whitelist, matching, result, &current_path);
+        }
+    }
+}
+
 fn compare_json_values(
     val1: &serde_json::Value,
     val2: &serde_json::Value,
     url: &str,
     whitelist: &WhitelistConfig,
+    matching: Option<&MatchingConfig>,
     result: &mut DiffResult,
     path: &str,
 ) {
@@ -457,48 +1178,241 @@ fn compare_json_values(
                 match (v1, v2) {
                     (Some(val1), Some(val2)) => {
                         if val1 != val2 {
-                            // Check if this key is whitelisted
-                            if whitelist.is_payload_key_whitelisted(key, url) {
+                            if matcher_matches(matching, &current_path, val1, val2) {
+                                // Conforms to the declared matcher; not a diff.
+                            } else if whitelist.is_payload_key_whitelisted(key, url) {
                                 result.has_whitelisted_diff = true;
+                                result.entries.push(DiffEntry {
+                                    path: current_path.clone(),
+                                    left_value: Some(val1.clone()),
+                                    right_value: Some(val2.clone()),
+                                    kind: "whitelisted".to_string(),
+                                });
                             } else {
                                 // Recursively check nested objects
-                                compare_json_values(val1, val2, url, whitelist, result, &current_path);
+                                compare_json_values(val1, val2, url, whitelist, matching, result, &current_path);
                             }
                         }
                     }
                     (None, Some(_)) | (Some(_), None) => {
                         // Key exists in only one object
-                        if whitelist.is_payload_key_whitelisted(key, url) {
+                        let whitelisted = whitelist.is_payload_key_whitelisted(key, url);
+                        if whitelisted {
                             result.has_whitelisted_diff = true;
                         } else {
                             result.has_non_whitelisted_diff = true;
                         }
+
+                        result.entries.push(DiffEntry {
+                            path: current_path.clone(),
+                            left_value: v1.cloned(),
+                            right_value: v2.cloned(),
+                            kind: diff_kind(whitelisted, v1.is_some(), v2.is_some()).to_string(),
+                        });
                     }
                     _ => {}
                 }
             }
         }
         (serde_json::Value::Array(arr1), serde_json::Value::Array(arr2)) => {
-            if arr1 != arr2 {
-                result.has_non_whitelisted_diff = true;
-            }
+            compare_json_arrays(arr1, arr2, url, whitelist, matching, result, path);
         }
         _ => {
             if val1 != val2 {
                 result.has_non_whitelisted_diff = true;
+                result.entries.push(DiffEntry {
+                    path: path.to_string(),
+                    left_value: Some(val1.clone()),
+                    right_value: Some(val2.clone()),
+                    kind: "changed".to_string(),
+                });
+            }
+        }
+    }
+}
+
+// Diffs two JSON arrays element-by-element instead of treating any change as
+// a single blunt diff. When every element is an object sharing an identity
+// key, elements are matched by that key so whitelisting/matchers still apply
+// inside them; otherwise falls back to an LCS alignment over the elements'
+// serialized form so reordering/insertion doesn't mark unrelated elements as
+// changed.
+fn compare_json_arrays(
+    arr1: &[serde_json::Value],
+    arr2: &[serde_json::Value],
+    url: &str,
+    whitelist: &WhitelistConfig,
+    matching: Option<&MatchingConfig>,
+    result: &mut DiffResult,
+    path: &str,
+) {
+    match array_identity_key(arr1, arr2) {
+        Some(key) => align_arrays_by_key(arr1, arr2, &key, url, whitelist, matching, result, path),
+        None => align_arrays_by_lcs(arr1, arr2, result, path),
+    }
+}
+
+const ARRAY_IDENTITY_KEY_CANDIDATES: [&str; 1] = ["id"];
+
+fn array_identity_key(arr1: &[serde_json::Value], arr2: &[serde_json::Value]) -> Option<String> {
+    if arr1.is_empty() && arr2.is_empty() {
+        return None;
+    }
+    if !arr1.iter().chain(arr2.iter()).all(|v| v.is_object()) {
+        return None;
+    }
+
+    ARRAY_IDENTITY_KEY_CANDIDATES
+        .iter()
+        .find(|key| arr1.iter().chain(arr2.iter()).all(|v| v.get(**key).is_some()))
+        .map(|key| key.to_string())
+}
+
+fn align_arrays_by_key(
+    arr1: &[serde_json::Value],
+    arr2: &[serde_json::Value],
+    key: &str,
+    url: &str,
+    whitelist: &WhitelistConfig,
+    matching: Option<&MatchingConfig>,
+    result: &mut DiffResult,
+    path: &str,
+) {
+    let mut used2 = vec![false; arr2.len()];
+
+    for item1 in arr1 {
+        let id1 = item1.get(key);
+        let found = arr2
+            .iter()
+            .enumerate()
+            .find(|(j, item2)| !used2[*j] && item2.get(key) == id1);
+
+        match found {
+            Some((j, item2)) => {
+                used2[j] = true;
+                if item1 != item2 {
+                    let indexed_path = format!("{}[{}={:?}]", path, key, id1);
+                    compare_json_values(item1, item2, url, whitelist, matching, result, &indexed_path);
+                }
+            }
+            None => {
+                // Present in arr1 only: a removed element.
+                result.has_non_whitelisted_diff = true;
+                result.entries.push(DiffEntry {
+                    path: format!("{}[{}={:?}]", path, key, id1),
+                    left_value: Some(item1.clone()),
+                    right_value: None,
+                    kind: "removed".to_string(),
+                });
             }
         }
     }
+
+    for (j, used) in used2.iter().enumerate() {
+        if !used {
+            // Present in arr2 only: an added element.
+            result.has_non_whitelisted_diff = true;
+            result.entries.push(DiffEntry {
+                path: format!("{}[{}={:?}]", path, key, arr2[j].get(key)),
+                left_value: None,
+                right_value: Some(arr2[j].clone()),
+                kind: "added".to_string(),
+            });
+        }
+    }
+}
+
+fn align_arrays_by_lcs(
+    arr1: &[serde_json::Value],
+    arr2: &[serde_json::Value],
+    result: &mut DiffResult,
+    path: &str,
+) {
+    let serialized1: Vec<String> = arr1.iter().map(|v| v.to_string()).collect();
+    let serialized2: Vec<String> = arr2.iter().map(|v| v.to_string()).collect();
+
+    let n = serialized1.len();
+    let m = serialized2.len();
+    let mut lengths = vec![vec![0usize; m + 1]; n + 1];
+
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lengths[i][j] = if serialized1[i] == serialized2[j] {
+                lengths[i + 1][j + 1] + 1
+            } else {
+                lengths[i + 1][j].max(lengths[i][j + 1])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    while i < n && j < m {
+        if serialized1[i] == serialized2[j] {
+            i += 1;
+            j += 1;
+        } else if lengths[i + 1][j] >= lengths[i][j + 1] {
+            result.has_non_whitelisted_diff = true; // removed from arr1
+            result.entries.push(DiffEntry {
+                path: format!("{}[{}]", path, i),
+                left_value: Some(arr1[i].clone()),
+                right_value: None,
+                kind: "removed".to_string(),
+            });
+            i += 1;
+        } else {
+            result.has_non_whitelisted_diff = true; // added in arr2
+            result.entries.push(DiffEntry {
+                path: format!("{}[{}]", path, j),
+                left_value: None,
+                right_value: Some(arr2[j].clone()),
+                kind: "added".to_string(),
+            });
+            j += 1;
+        }
+    }
+
+    while i < n {
+        result.has_non_whitelisted_diff = true;
+        result.entries.push(DiffEntry {
+            path: format!("{}[{}]", path, i),
+            left_value: Some(arr1[i].clone()),
+            right_value: None,
+            kind: "removed".to_string(),
+        });
+        i += 1;
+    }
+    while j < m {
+        result.has_non_whitelisted_diff = true;
+        result.entries.push(DiffEntry {
+            path: format!("{}[{}]", path, j),
+            left_value: None,
+            right_value: Some(arr2[j].clone()),
+            kind: "added".to_string(),
+        });
+        j += 1;
+    }
 }
 
 pub fn align_requests(requests1: &[HarRequest], requests2: &[HarRequest]) -> Vec<AlignedPair> {
-    align_requests_with_whitelist(requests1, requests2, None)
+    align_requests_with_whitelist(requests1, requests2, None, None)
 }
 
 pub fn align_requests_with_whitelist(
     requests1: &[HarRequest],
     requests2: &[HarRequest],
     whitelist: Option<&WhitelistConfig>,
+    matching: Option<&MatchingConfig>,
+) -> Vec<AlignedPair> {
+    align_requests_with_whitelist_with_progress(requests1, requests2, whitelist, matching, None, None)
+}
+
+pub fn align_requests_with_whitelist_with_progress(
+    requests1: &[HarRequest],
+    requests2: &[HarRequest],
+    whitelist: Option<&WhitelistConfig>,
+    matching: Option<&MatchingConfig>,
+    mut on_progress: Option<&mut ProgressCallback>,
+    should_cancel: Option<&CancelCheck>,
 ) -> Vec<AlignedPair> {
     let default_config = WhitelistConfig::new();
     let config = whitelist.unwrap_or(&default_config);
@@ -506,18 +1420,27 @@ pub fn align_requests_with_whitelist(
     // Simple alignment algorithm - match by path
     let mut aligned = Vec::new();
     let mut used2 = vec![false; requests2.len()];
+    let total = requests1.len();
+
+    // Emit progress at most every PROGRESS_STEP requests so large captures
+    // don't flood the UI with events.
+    const PROGRESS_STEP: usize = 50;
 
     for (i, req1) in requests1.iter().enumerate() {
+        if should_cancel.map(|cancel| cancel()).unwrap_or(false) {
+            break;
+        }
+
         let mut found_match = false;
 
         // Look for matching path in requests2
         for (j, req2) in requests2.iter().enumerate() {
-            if !used2[j] && req1.path == req2.path {
+            if !used2[j] && req1.normalized_path == req2.normalized_path {
                 used2[j] = true;
                 aligned.push(AlignedPair {
                     index1: Some(i),
                     index2: Some(j),
-                    comparison: Some(compare_requests_with_whitelist(req1, req2, false, config)),
+                    comparison: Some(compare_requests_with_whitelist(req1, req2, false, config, matching)),
                 });
                 found_match = true;
                 break;
@@ -531,6 +1454,12 @@ pub fn align_requests_with_whitelist(
                 comparison: None,
             });
         }
+
+        if let Some(cb) = on_progress.as_deref_mut() {
+            if (i + 1) % PROGRESS_STEP == 0 || i + 1 == total {
+                cb(i + 1, total);
+            }
+        }
     }
 
     // Interleave unmatched requests from requests2 into their proper positions
@@ -560,124 +1489,173 @@ pub fn align_requests_with_whitelist(
 
 // VS Code-like alignment using sequence matching
 pub fn align_requests_like_vscode(requests1: &[HarRequest], requests2: &[HarRequest]) -> Vec<AlignedPair> {
-    align_requests_like_vscode_with_whitelist(requests1, requests2, None)
+    align_requests_like_vscode_with_whitelist(requests1, requests2, None, None)
 }
 
 pub fn align_requests_like_vscode_with_whitelist(
     requests1: &[HarRequest],
     requests2: &[HarRequest],
     whitelist: Option<&WhitelistConfig>,
+    matching: Option<&MatchingConfig>,
+) -> Vec<AlignedPair> {
+    align_requests_like_vscode_with_whitelist_with_progress(requests1, requests2, whitelist, matching, None, None)
+}
+
+pub fn align_requests_like_vscode_with_whitelist_with_progress(
+    requests1: &[HarRequest],
+    requests2: &[HarRequest],
+    whitelist: Option<&WhitelistConfig>,
+    matching: Option<&MatchingConfig>,
+    mut on_progress: Option<&mut ProgressCallback>,
+    should_cancel: Option<&CancelCheck>,
 ) -> Vec<AlignedPair> {
     let default_config = WhitelistConfig::new();
     let config = whitelist.unwrap_or(&default_config);
 
     // Create path sequences for comparison
-    let paths1: Vec<&str> = requests1.iter().map(|r| r.path.as_str()).collect();
-    let paths2: Vec<&str> = requests2.iter().map(|r| r.path.as_str()).collect();
+    let paths1: Vec<&str> = requests1.iter().map(|r| r.normalized_path.as_str()).collect();
+    let paths2: Vec<&str> = requests2.iter().map(|r| r.normalized_path.as_str()).collect();
 
-    // Improved LCS-based alignment
-    let mut aligned = Vec::new();
-    let mut i = 0;
-    let mut j = 0;
+    // The DP table below is O(n*m) memory; above this size fall back to a
+    // greedy nearest-match alignment so huge captures don't exhaust memory.
+    const LCS_TABLE_CELL_LIMIT: usize = 4_000_000;
 
-    while i < paths1.len() || j < paths2.len() {
-        if i >= paths1.len() {
-            // Exhausted list1, add remaining items from list2
-            aligned.push(AlignedPair {
-                index1: None,
+    let ops = if paths1.len().saturating_mul(paths2.len()) <= LCS_TABLE_CELL_LIMIT {
+        lcs_align_ops(&paths1, &paths2)
+    } else {
+        greedy_align_ops(&paths1, &paths2)
+    };
+
+    let total = ops.len();
+    // Emit progress at most every PROGRESS_STEP ops so large captures don't
+    // flood the UI with events.
+    const PROGRESS_STEP: usize = 50;
+    let mut result = Vec::with_capacity(total);
+
+    for (idx, op) in ops.into_iter().enumerate() {
+        if should_cancel.map(|cancel| cancel()).unwrap_or(false) {
+            break;
+        }
+
+        result.push(match op {
+            AlignOp::Match(i, j) => AlignedPair {
+                index1: Some(i),
                 index2: Some(j),
-                comparison: None,
-            });
-            j += 1;
-        } else if j >= paths2.len() {
-            // Exhausted list2, add remaining items from list1
-            aligned.push(AlignedPair {
+                comparison: Some(compare_requests_with_whitelist(&requests1[i], &requests2[j], false, config, matching)),
+            },
+            AlignOp::Left(i) => AlignedPair {
                 index1: Some(i),
                 index2: None,
                 comparison: None,
-            });
-            i += 1;
-        } else if paths1[i] == paths2[j] {
-            // Match found at current position
-            let comparison = Some(compare_requests_with_whitelist(&requests1[i], &requests2[j], false, config));
-            aligned.push(AlignedPair {
-                index1: Some(i),
+            },
+            AlignOp::Right(j) => AlignedPair {
+                index1: None,
                 index2: Some(j),
-                comparison,
-            });
+                comparison: None,
+            },
+        });
+
+        if let Some(cb) = on_progress.as_deref_mut() {
+            if (idx + 1) % PROGRESS_STEP == 0 || idx + 1 == total {
+                cb(idx + 1, total);
+            }
+        }
+    }
+
+    result
+}
+
+enum AlignOp {
+    Match(usize, usize),
+    Left(usize),
+    Right(usize),
+}
+
+// True LCS alignment: builds the standard DP table `lengths[i][j]` = length
+// of the LCS of paths1[i..] and paths2[j..], then backtracks from (0, 0),
+// preferring to advance whichever side keeps the longer remaining LCS. This
+// yields a minimal alignment, unlike the old greedy lookahead it replaces.
+fn lcs_align_ops(paths1: &[&str], paths2: &[&str]) -> Vec<AlignOp> {
+    let n = paths1.len();
+    let m = paths2.len();
+    let mut lengths = vec![vec![0usize; m + 1]; n + 1];
+
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lengths[i][j] = if paths1[i] == paths2[j] {
+                lengths[i + 1][j + 1] + 1
+            } else {
+                lengths[i + 1][j].max(lengths[i][j + 1])
+            };
+        }
+    }
+
+    let mut ops = Vec::new();
+    let (mut i, mut j) = (0, 0);
+
+    while i < n && j < m {
+        if paths1[i] == paths2[j] {
+            ops.push(AlignOp::Match(i, j));
             i += 1;
             j += 1;
+        } else if lengths[i + 1][j] >= lengths[i][j + 1] {
+            ops.push(AlignOp::Left(i));
+            i += 1;
         } else {
-            // No match at current position - look ahead to decide what to do
-            let path1_in_list2 = paths2[j..].iter().position(|&p| p == paths1[i]);
-            let path2_in_list1 = paths1[i..].iter().position(|&p| p == paths2[j]);
-
-            match (path1_in_list2, path2_in_list1) {
-                (Some(pos1), Some(pos2)) => {
-                    // Both items appear later in the other list
-                    // Choose the one that appears sooner
-                    if pos1 <= pos2 {
-                        // Current item from list2 appears sooner in list1, so insert it as unmatched
-                        aligned.push(AlignedPair {
-                            index1: None,
-                            index2: Some(j),
-                            comparison: None,
-                        });
-                        j += 1;
-                    } else {
-                        // Current item from list1 appears sooner in list2, so insert it as unmatched
-                        aligned.push(AlignedPair {
-                            index1: Some(i),
-                            index2: None,
-                            comparison: None,
-                        });
-                        i += 1;
-                    }
-                }
-                (Some(_), None) => {
-                    // List1 item appears later in list2, but list2 item doesn't appear in list1
-                    // Insert list2 item as unmatched
-                    aligned.push(AlignedPair {
-                        index1: None,
-                        index2: Some(j),
-                        comparison: None,
-                    });
-                    j += 1;
-                }
-                (None, Some(_)) => {
-                    // List2 item appears later in list1, but list1 item doesn't appear in list2
-                    // Insert list1 item as unmatched
-                    aligned.push(AlignedPair {
-                        index1: Some(i),
-                        index2: None,
-                        comparison: None,
-                    });
-                    i += 1;
-                }
-                (None, None) => {
-                    // Neither item appears in the other list
-                    // Insert left item as unmatched
-                    aligned.push(AlignedPair {
-                        index1: Some(i),
-                        index2: None,
-                        comparison: None,
-                    });
-                    i += 1;
-                    // Also insert right item as unmatched if j is still in bounds
-                    if j < paths2.len() {
-                        aligned.push(AlignedPair {
-                            index1: None,
-                            index2: Some(j),
-                            comparison: None,
-                        });
-                        j += 1;
+            ops.push(AlignOp::Right(j));
+            j += 1;
+        }
+    }
+
+    while i < n {
+        ops.push(AlignOp::Left(i));
+        i += 1;
+    }
+    while j < m {
+        ops.push(AlignOp::Right(j));
+        j += 1;
+    }
+
+    ops
+}
+
+// Bounded-memory fallback for very large flows: scans forward, matching
+// identical paths greedily instead of computing the full O(n*m) DP table.
+fn greedy_align_ops(paths1: &[&str], paths2: &[&str]) -> Vec<AlignOp> {
+    let mut ops = Vec::new();
+    let mut used2 = vec![false; paths2.len()];
+    let mut next_search_start = 0usize;
+
+    for (i, path1) in paths1.iter().enumerate() {
+        let found = paths2[next_search_start..]
+            .iter()
+            .position(|p| p == path1)
+            .map(|offset| next_search_start + offset)
+            .filter(|&j| !used2[j]);
+
+        match found {
+            Some(j) => {
+                used2[j] = true;
+                for k in next_search_start..j {
+                    if !used2[k] {
+                        ops.push(AlignOp::Right(k));
+                        used2[k] = true;
                     }
                 }
+                ops.push(AlignOp::Match(i, j));
+                next_search_start = j + 1;
             }
+            None => ops.push(AlignOp::Left(i)),
         }
     }
 
-    aligned
+    for (j, used) in used2.into_iter().enumerate() {
+        if !used {
+            ops.push(AlignOp::Right(j));
+        }
+    }
+
+    ops
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -689,6 +1667,9 @@ pub struct DetailedComparison {
     pub params: ComparisonSection,
     pub response: ComparisonSection,
     pub response_body: Option<ComparisonSection>,
+    // Flat, key-addressable differences (headers + request body), for
+    // consumers that want machine-readable results rather than rendered text.
+    pub diff_entries: Vec<DiffEntry>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -715,6 +1696,16 @@ pub fn create_detailed_comparison_with_whitelist(
     req2: &HarRequest,
     keys_only: bool,
     whitelist: &WhitelistConfig,
+) -> DetailedComparison {
+    create_detailed_comparison_with_rules(req1, req2, keys_only, whitelist, None)
+}
+
+pub fn create_detailed_comparison_with_rules(
+    req1: &HarRequest,
+    req2: &HarRequest,
+    keys_only: bool,
+    whitelist: &WhitelistConfig,
+    matching: Option<&MatchingConfig>,
 ) -> DetailedComparison {
     DetailedComparison {
         general: create_general_section(req1, req2, whitelist),
@@ -724,9 +1715,84 @@ pub fn create_detailed_comparison_with_whitelist(
         params: create_params_section(req1, req2, keys_only, whitelist),
         response: create_response_section(req1, req2, keys_only, whitelist),
         response_body: create_response_body_section(req1, req2, whitelist),
+        diff_entries: diff_entries_for_requests(req1, req2, whitelist, matching),
     }
 }
 
+// Flat dot-path entries for the request side of the comparison (headers +
+// body), built from the same comparators `compare_requests_with_whitelist`
+// uses so the two stay in sync.
+fn diff_entries_for_requests(
+    req1: &HarRequest,
+    req2: &HarRequest,
+    whitelist: &WhitelistConfig,
+    matching: Option<&MatchingConfig>,
+) -> Vec<DiffEntry> {
+    let mut entries = compare_headers_with_whitelist(&req1.headers, &req2.headers, &req1.url, whitelist, matching).entries;
+
+    if let (Some(data1), Some(data2)) = (&req1.post_data, &req2.post_data) {
+        let content_type = header_value(&req1.headers, "Content-Type");
+        entries.extend(compare_payload_with_whitelist(data1, data2, &req1.url, whitelist, matching, content_type).entries);
+    }
+
+    entries
+}
+
+// Line-by-line diff of the two rendered section strings, used to populate
+// `ComparisonSection::differences` for the exported report. Only lines that
+// actually differ are included, so an all-same section renders as "No
+// differences." Whitelisted keys are resolved per line ("key: value") rather
+// than tracked at the char level, matching how whitelisted_keys is collected.
+fn diff_lines(content1: &str, content2: &str, whitelisted_keys: &[String]) -> Vec<DiffLine> {
+    let lines1: Vec<&str> = content1.lines().collect();
+    let lines2: Vec<&str> = content2.lines().collect();
+    let max_len = lines1.len().max(lines2.len());
+
+    (0..max_len)
+        .filter_map(|i| {
+            let line1 = lines1.get(i).copied();
+            let line2 = lines2.get(i).copied();
+            match (line1, line2) {
+                (Some(l1), Some(l2)) if l1 == l2 => None,
+                (Some(l1), Some(l2)) => {
+                    let diff_type = if line_key_is_whitelisted(l1, whitelisted_keys)
+                        || line_key_is_whitelisted(l2, whitelisted_keys)
+                    {
+                        "whitelisted"
+                    } else {
+                        "different"
+                    };
+                    Some(DiffLine {
+                        line_number: i + 1,
+                        diff_type: diff_type.to_string(),
+                        content: format!("{} | {}", l1, l2),
+                    })
+                }
+                (Some(l1), None) => Some(DiffLine {
+                    line_number: i + 1,
+                    diff_type: "missing".to_string(),
+                    content: format!("{} | (missing)", l1),
+                }),
+                (None, Some(l2)) => Some(DiffLine {
+                    line_number: i + 1,
+                    diff_type: "missing".to_string(),
+                    content: format!("(missing) | {}", l2),
+                }),
+                (None, None) => None,
+            }
+        })
+        .collect()
+}
+
+// Handles both "Header-Name: value" lines and pretty-printed JSON
+// '"key": value' lines, since both header and payload sections reuse this.
+fn line_key_is_whitelisted(line: &str, whitelisted_keys: &[String]) -> bool {
+    line.split_once(':')
+        .map(|(key, _)| key.trim().trim_matches('"').to_lowercase())
+        .map(|key| whitelisted_keys.contains(&key))
+        .unwrap_or(false)
+}
+
 fn create_general_section(req1: &HarRequest, req2: &HarRequest, _whitelist: &WhitelistConfig) -> ComparisonSection {
     let content1 = format!(
         "Index: {}\nMethod: {}\nURL: {}\nPath: {}\nResponse Status: {}",
@@ -737,10 +1803,12 @@ fn create_general_section(req1: &HarRequest, req2: &HarRequest, _whitelist: &Whi
         req2.index, req2.method, req2.url, req2.path, req2.response_status
     );
 
+    let differences = diff_lines(&content1, &content2, &[]);
+
     ComparisonSection {
         content1,
         content2,
-        differences: vec![],
+        differences,
         whitelisted_keys: vec![],
     }
 }
@@ -758,10 +1826,12 @@ fn create_headers_section(req1: &HarRequest, req2: &HarRequest, _keys_only: bool
         }
     }
 
+    let differences = diff_lines(&content1, &content2, &whitelisted_keys);
+
     ComparisonSection {
         content1,
         content2,
-        differences: vec![],
+        differences,
         whitelisted_keys,
     }
 }
@@ -770,10 +1840,12 @@ fn create_params_section(req1: &HarRequest, req2: &HarRequest, _keys_only: bool,
     let content1 = format_params(&req1.query_params);
     let content2 = format_params(&req2.query_params);
 
+    let differences = diff_lines(&content1, &content2, &[]);
+
     ComparisonSection {
         content1,
         content2,
-        differences: vec![],
+        differences,
         whitelisted_keys: vec![],
     }
 }
@@ -803,10 +1875,12 @@ fn create_response_section(req1: &HarRequest, req2: &HarRequest, _keys_only: boo
         }
     }
 
+    let differences = diff_lines(&content1, &content2, &whitelisted_keys);
+
     ComparisonSection {
         content1,
         content2,
-        differences: vec![],
+        differences,
         whitelisted_keys,
     }
 }
@@ -824,10 +1898,12 @@ fn create_raw_request_section(req1: &HarRequest, req2: &HarRequest, whitelist: &
         }
     }
 
+    let differences = diff_lines(&content1, &content2, &whitelisted_keys);
+
     ComparisonSection {
         content1,
         content2,
-        differences: vec![],
+        differences,
         whitelisted_keys,
     }
 }
@@ -862,10 +1938,12 @@ fn create_payloads_section(req1: &HarRequest, req2: &HarRequest, whitelist: &Whi
             }
         }
 
+        let differences = diff_lines(&content1, &content2, &whitelisted_keys);
+
         Some(ComparisonSection {
             content1,
             content2,
-            differences: vec![],
+            differences,
             whitelisted_keys,
         })
     } else {
@@ -1015,13 +2093,235 @@ fn create_response_body_section(req1: &HarRequest, req2: &HarRequest, whitelist:
             }
         }
 
+        let differences = diff_lines(&content1, &content2, &whitelisted_keys);
+
         Some(ComparisonSection {
             content1,
             content2,
-            differences: vec![],
+            differences,
             whitelisted_keys,
         })
     } else {
         None
     }
 }
+
+// Renders an alignment as a Graphviz digraph: one node per request, edges
+// between matched pairs, colored by comparison status, and dangling nodes
+// (no edge) for unmatched entries on either side.
+pub fn export_alignment_to_dot(
+    requests1: &[HarRequest],
+    requests2: &[HarRequest],
+    aligned: &[AlignedPair],
+) -> String {
+    let mut dot = String::from("digraph FlowAlignment {\n    rankdir=LR;\n    node [shape=box, style=filled, fontname=\"monospace\"];\n\n");
+
+    for (idx, pair) in aligned.iter().enumerate() {
+        if let Some(i) = pair.index1 {
+            let req = &requests1[i];
+            dot.push_str(&format!(
+                "    l{} [label=\"#{} {} {}\", fillcolor=\"{}\"];\n",
+                idx,
+                req.index,
+                escape_dot_label(&req.method),
+                escape_dot_label(&req.path),
+                alignment_node_color(pair.comparison.as_ref()),
+            ));
+        }
+        if let Some(j) = pair.index2 {
+            let req = &requests2[j];
+            dot.push_str(&format!(
+                "    r{} [label=\"#{} {} {}\", fillcolor=\"{}\"];\n",
+                idx,
+                req.index,
+                escape_dot_label(&req.method),
+                escape_dot_label(&req.path),
+                alignment_node_color(pair.comparison.as_ref()),
+            ));
+        }
+    }
+
+    dot.push('\n');
+
+    for (idx, pair) in aligned.iter().enumerate() {
+        if pair.index1.is_some() && pair.index2.is_some() {
+            dot.push_str(&format!("    l{} -> r{};\n", idx, idx));
+        }
+    }
+
+    dot.push_str("}\n");
+    dot
+}
+
+fn alignment_node_color(comparison: Option<&ComparisonResult>) -> &'static str {
+    match comparison.map(|c| c.status.as_str()) {
+        Some("match") => "#b7e4c7",
+        Some("whitelisted") => "#ffe8a3",
+        Some("partial") | Some("different") => "#f4a6a6",
+        _ => "#d9d9d9",
+    }
+}
+
+fn escape_dot_label(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+// Renders a `DetailedComparison` as a standalone report. Returns the
+// rendered content together with the file extension a save dialog should
+// suggest. Errors on any format other than the three supported below so
+// callers can surface a clear message instead of silently no-op-ing.
+pub fn render_comparison_report(
+    comparison: &DetailedComparison,
+    format: &str,
+) -> Result<(String, &'static str), String> {
+    match format.to_lowercase().as_str() {
+        "json" => {
+            let content = serde_json::to_string_pretty(comparison)
+                .map_err(|e| format!("Failed to serialize comparison report: {}", e))?;
+            Ok((content, "json"))
+        }
+        "markdown" | "md" => Ok((render_comparison_markdown(comparison), "md")),
+        "html" => Ok((render_comparison_html(comparison), "html")),
+        other => Err(format!(
+            "Unsupported report format '{}': expected one of json, markdown, html",
+            other
+        )),
+    }
+}
+
+fn render_comparison_markdown(comparison: &DetailedComparison) -> String {
+    let mut out = String::new();
+    out.push_str("# Flow Comparison Report\n\n");
+
+    render_markdown_section(&mut out, "General", &comparison.general);
+    render_markdown_section(&mut out, "Raw Request", &comparison.raw_request);
+    render_markdown_section(&mut out, "Headers", &comparison.headers);
+    render_markdown_section(&mut out, "Query Params", &comparison.params);
+    if let Some(payloads) = &comparison.payloads {
+        render_markdown_section(&mut out, "Payload", payloads);
+    }
+    render_markdown_section(&mut out, "Response", &comparison.response);
+    if let Some(response_body) = &comparison.response_body {
+        render_markdown_section(&mut out, "Response Body", response_body);
+    }
+
+    if !comparison.diff_entries.is_empty() {
+        out.push_str("## Diff Entries\n\n");
+        out.push_str("| Path | Kind | Left | Right |\n");
+        out.push_str("|---|---|---|---|\n");
+        for entry in &comparison.diff_entries {
+            out.push_str(&format!(
+                "| {} | {} | {} | {} |\n",
+                entry.path,
+                entry.kind,
+                entry.left_value.as_ref().map(report_value_to_string).unwrap_or_else(|| "-".to_string()),
+                entry.right_value.as_ref().map(report_value_to_string).unwrap_or_else(|| "-".to_string()),
+            ));
+        }
+        out.push('\n');
+    }
+
+    out
+}
+
+fn render_markdown_section(out: &mut String, title: &str, section: &ComparisonSection) {
+    out.push_str(&format!("## {}\n\n", title));
+
+    if !section.whitelisted_keys.is_empty() {
+        out.push_str(&format!("Whitelisted: {}\n\n", section.whitelisted_keys.join(", ")));
+    }
+
+    if section.differences.is_empty() {
+        out.push_str("No differences.\n\n");
+        return;
+    }
+
+    out.push_str("| Line | Type | Content |\n");
+    out.push_str("|---|---|---|\n");
+    for line in &section.differences {
+        out.push_str(&format!(
+            "| {} | {} | {} |\n",
+            line.line_number,
+            line.diff_type,
+            line.content.replace('|', "\\|").replace('\n', " "),
+        ));
+    }
+    out.push('\n');
+}
+
+fn render_comparison_html(comparison: &DetailedComparison) -> String {
+    let mut out = String::new();
+    out.push_str("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>Flow Comparison Report</title>\n");
+    out.push_str("<style>body{font-family:monospace;margin:2rem;} table{border-collapse:collapse;width:100%;margin-bottom:1.5rem;} td,th{border:1px solid #ccc;padding:4px 8px;text-align:left;} tr.different td,tr.partial td{background:#f4a6a6;} tr.whitelisted td{background:#ffe8a3;} tr.missing td{background:#d9d9d9;} h2{margin-top:2rem;}</style>\n");
+    out.push_str("</head>\n<body>\n<h1>Flow Comparison Report</h1>\n");
+
+    render_html_section(&mut out, "General", &comparison.general);
+    render_html_section(&mut out, "Raw Request", &comparison.raw_request);
+    render_html_section(&mut out, "Headers", &comparison.headers);
+    render_html_section(&mut out, "Query Params", &comparison.params);
+    if let Some(payloads) = &comparison.payloads {
+        render_html_section(&mut out, "Payload", payloads);
+    }
+    render_html_section(&mut out, "Response", &comparison.response);
+    if let Some(response_body) = &comparison.response_body {
+        render_html_section(&mut out, "Response Body", response_body);
+    }
+
+    if !comparison.diff_entries.is_empty() {
+        out.push_str("<h2>Diff Entries</h2>\n<table>\n<tr><th>Path</th><th>Kind</th><th>Left</th><th>Right</th></tr>\n");
+        for entry in &comparison.diff_entries {
+            out.push_str(&format!(
+                "<tr class=\"{}\"><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+                escape_html(&entry.kind),
+                escape_html(&entry.path),
+                escape_html(&entry.kind),
+                entry.left_value.as_ref().map(report_value_to_string).map(|s| escape_html(&s)).unwrap_or_else(|| "-".to_string()),
+                entry.right_value.as_ref().map(report_value_to_string).map(|s| escape_html(&s)).unwrap_or_else(|| "-".to_string()),
+            ));
+        }
+        out.push_str("</table>\n");
+    }
+
+    out.push_str("</body>\n</html>\n");
+    out
+}
+
+fn render_html_section(out: &mut String, title: &str, section: &ComparisonSection) {
+    out.push_str(&format!("<h2>{}</h2>\n", escape_html(title)));
+
+    if !section.whitelisted_keys.is_empty() {
+        out.push_str(&format!("<p>Whitelisted: {}</p>\n", escape_html(&section.whitelisted_keys.join(", "))));
+    }
+
+    if section.differences.is_empty() {
+        out.push_str("<p>No differences.</p>\n");
+        return;
+    }
+
+    out.push_str("<table>\n<tr><th>Line</th><th>Type</th><th>Content</th></tr>\n");
+    for line in &section.differences {
+        out.push_str(&format!(
+            "<tr class=\"{}\"><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+            escape_html(&line.diff_type),
+            line.line_number,
+            escape_html(&line.diff_type),
+            escape_html(&line.content),
+        ));
+    }
+    out.push_str("</table>\n");
+}
+
+fn escape_html(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn report_value_to_string(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
@@ -1,9 +1,13 @@
 mod har;
 
-use har::{HarFile, HarRequest, AlignedPair, ComparisonResult, DetailedComparison, WhitelistConfig, parse_har_file, compare_requests, compare_requests_with_whitelist, align_requests_with_whitelist, align_requests_like_vscode_with_whitelist, create_detailed_comparison_with_whitelist, parse_whitelist_config};
+use har::{HarFile, HarRequest, AlignedPair, ComparisonResult, DetailedComparison, WhitelistConfig, MatchingConfig, NormalizationOptions, parse_har_file_with_progress, compare_requests, compare_requests_with_whitelist, align_requests_with_whitelist_with_progress, align_requests_like_vscode_with_whitelist_with_progress, create_detailed_comparison_with_rules, render_comparison_report, export_alignment_to_dot, parse_whitelist_config, parse_matching_config, parse_normalization_config, renormalize_request};
+use serde::Serialize;
 use std::fs;
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Mutex, LazyLock};
+use tauri::Emitter;
+use tauri_plugin_store::StoreExt;
 
 // Global storage for comparison data
 static COMPARISON_DATA_STORE: LazyLock<Mutex<HashMap<String, String>>> = LazyLock::new(|| Mutex::new(HashMap::new()));
@@ -11,6 +15,62 @@ static COMPARISON_DATA_STORE: LazyLock<Mutex<HashMap<String, String>>> = LazyLoc
 // Global storage for whitelist config
 static WHITELIST_CONFIG: LazyLock<Mutex<WhitelistConfig>> = LazyLock::new(|| Mutex::new(WhitelistConfig::new()));
 
+// Global storage for pact-style matching rules
+static MATCHING_CONFIG: LazyLock<Mutex<MatchingConfig>> = LazyLock::new(|| Mutex::new(MatchingConfig::new()));
+
+// Global storage for path/URL normalization options used while parsing HAR files
+static NORMALIZATION_CONFIG: LazyLock<Mutex<NormalizationOptions>> = LazyLock::new(|| Mutex::new(NormalizationOptions::default()));
+
+// Set by `cancel_align` and polled from inside the alignment loops so a
+// long-running alignment of a huge capture can be aborted from the UI.
+static ALIGN_CANCELLED: AtomicBool = AtomicBool::new(false);
+
+#[derive(Clone, Serialize)]
+struct ProgressEvent {
+    done: usize,
+    total: usize,
+}
+
+#[derive(Clone, Serialize)]
+struct DownloadProgressEvent {
+    downloaded: u64,
+    total: Option<u64>,
+}
+
+#[derive(Clone, Serialize)]
+struct BatchProgressEvent {
+    file_path: String,
+    file_index: usize,
+    file_count: usize,
+    done: usize,
+    total: usize,
+}
+
+// Refuse HAR downloads past this size so a misbehaving or malicious server
+// can't exhaust memory on the client.
+const MAX_HAR_DOWNLOAD_BYTES: u64 = 200 * 1024 * 1024;
+
+// Store file names used to persist state across app restarts via tauri-plugin-store
+const WHITELIST_STORE_FILE: &str = "whitelist-config.json";
+const COMPARISON_STORE_FILE: &str = "comparison-data.json";
+const WHITELIST_STORE_KEY: &str = "config";
+
+fn snapshot_normalization_config() -> NormalizationOptions {
+    NORMALIZATION_CONFIG.lock().map(|guard| guard.clone()).unwrap_or_default()
+}
+
+fn persist_whitelist_config(app: &tauri::AppHandle, config: &WhitelistConfig) -> Result<(), String> {
+    let store = app
+        .store(WHITELIST_STORE_FILE)
+        .map_err(|e| format!("Failed to open whitelist store: {}", e))?;
+    let value = serde_json::to_value(config)
+        .map_err(|e| format!("Failed to serialize whitelist config: {}", e))?;
+    store.set(WHITELIST_STORE_KEY, value);
+    store
+        .save()
+        .map_err(|e| format!("Failed to save whitelist store: {}", e))
+}
+
 #[tauri::command]
 async fn open_har_file(app: tauri::AppHandle) -> Result<Option<HarFile>, String> {
     use tauri_plugin_dialog::DialogExt;
@@ -26,7 +86,11 @@ async fn open_har_file(app: tauri::AppHandle) -> Result<Option<HarFile>, String>
             let path_str = path.to_string();
             match fs::read_to_string(&path_str) {
                 Ok(content) => {
-                    match parse_har_file(&content) {
+                    let mut on_progress = |done: usize, total: usize| {
+                        let _ = app.emit("har_parse_progress", ProgressEvent { done, total });
+                    };
+                    let normalization = snapshot_normalization_config();
+                    match parse_har_file_with_progress(&content, Some(&normalization), Some(&mut on_progress)) {
                         Ok(requests) => Ok(Some(HarFile {
                             requests,
                             file_path: path_str,
@@ -41,6 +105,105 @@ async fn open_har_file(app: tauri::AppHandle) -> Result<Option<HarFile>, String>
     }
 }
 
+#[tauri::command]
+async fn open_har_files(app: tauri::AppHandle) -> Result<Vec<HarFile>, String> {
+    use tauri_plugin_dialog::DialogExt;
+
+    let file_paths = app
+        .dialog()
+        .file()
+        .add_filter("HAR files", &["har"])
+        .blocking_pick_files();
+
+    match file_paths {
+        Some(paths) => {
+            let mut files = Vec::new();
+            let normalization = snapshot_normalization_config();
+            for path in paths {
+                let path_str = path.to_string();
+                let content = fs::read_to_string(&path_str)
+                    .map_err(|e| format!("Failed to read file {}: {}", path_str, e))?;
+                let requests = parse_har_file_with_progress(&content, Some(&normalization), None)
+                    .map_err(|e| format!("Failed to parse HAR file {}: {}", path_str, e))?;
+                files.push(HarFile {
+                    requests,
+                    file_path: path_str,
+                });
+            }
+            Ok(files)
+        }
+        None => Ok(Vec::new()), // User cancelled
+    }
+}
+
+#[tauri::command]
+async fn fetch_har_from_url(app: tauri::AppHandle, url: String) -> Result<HarFile, String> {
+    use futures_util::StreamExt;
+    use tauri_plugin_http::reqwest;
+
+    let response = reqwest::get(&url)
+        .await
+        .map_err(|e| format!("Failed to fetch '{}': {}", url, e))?;
+
+    if !response.status().is_success() {
+        return Err(format!(
+            "Failed to fetch '{}': server returned {}",
+            url,
+            response.status()
+        ));
+    }
+
+    let total = response.content_length();
+    if let Some(total) = total {
+        if total > MAX_HAR_DOWNLOAD_BYTES {
+            return Err(format!(
+                "Refusing to download '{}': {} bytes exceeds the {} byte limit",
+                url, total, MAX_HAR_DOWNLOAD_BYTES
+            ));
+        }
+    }
+
+    let mut downloaded: u64 = 0;
+    let mut bytes = Vec::new();
+    let mut stream = response.bytes_stream();
+
+    // Emit progress at most every PROGRESS_CHUNK bytes so large downloads
+    // don't flood the UI with events.
+    const PROGRESS_CHUNK: u64 = 256 * 1024;
+    let mut next_emit = PROGRESS_CHUNK;
+
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk.map_err(|e| format!("Failed while downloading '{}': {}", url, e))?;
+        downloaded += chunk.len() as u64;
+
+        if downloaded > MAX_HAR_DOWNLOAD_BYTES {
+            return Err(format!(
+                "Refusing to download '{}': exceeded the {} byte limit",
+                url, MAX_HAR_DOWNLOAD_BYTES
+            ));
+        }
+
+        bytes.extend_from_slice(&chunk);
+
+        if downloaded >= next_emit || total.map(|t| downloaded >= t).unwrap_or(false) {
+            let _ = app.emit("har_download_progress", DownloadProgressEvent { downloaded, total });
+            next_emit = downloaded + PROGRESS_CHUNK;
+        }
+    }
+
+    let content = String::from_utf8(bytes)
+        .map_err(|e| format!("Downloaded content from '{}' was not valid UTF-8: {}", url, e))?;
+
+    let normalization = snapshot_normalization_config();
+    let requests = parse_har_file_with_progress(&content, Some(&normalization), None)
+        .map_err(|e| format!("Failed to parse HAR content from '{}': {}", url, e))?;
+
+    Ok(HarFile {
+        requests,
+        file_path: url,
+    })
+}
+
 #[tauri::command]
 async fn load_whitelist_config(app: tauri::AppHandle) -> Result<bool, String> {
     use tauri_plugin_dialog::DialogExt;
@@ -58,6 +221,7 @@ async fn load_whitelist_config(app: tauri::AppHandle) -> Result<bool, String> {
                 Ok(content) => {
                     match parse_whitelist_config(&content) {
                         Ok(config) => {
+                            persist_whitelist_config(&app, &config)?;
                             match WHITELIST_CONFIG.lock() {
                                 Ok(mut whitelist) => {
                                     *whitelist = config;
@@ -77,62 +241,392 @@ async fn load_whitelist_config(app: tauri::AppHandle) -> Result<bool, String> {
 }
 
 #[tauri::command]
-fn clear_whitelist_config() -> Result<(), String> {
+async fn save_whitelist_config(app: tauri::AppHandle) -> Result<bool, String> {
+    use tauri_plugin_dialog::DialogExt;
+
+    let config = match WHITELIST_CONFIG.lock() {
+        Ok(whitelist) => whitelist.clone(),
+        Err(e) => return Err(format!("Failed to read whitelist config: {}", e)),
+    };
+    let content = serde_json::to_string_pretty(&config)
+        .map_err(|e| format!("Failed to serialize whitelist config: {}", e))?;
+
+    let file_path = app
+        .dialog()
+        .file()
+        .add_filter("JSON files", &["json"])
+        .blocking_save_file();
+
+    match file_path {
+        Some(path) => {
+            fs::write(path.to_string(), content).map_err(|e| format!("Failed to write file: {}", e))?;
+            Ok(true)
+        }
+        None => Ok(false), // User cancelled
+    }
+}
+
+#[tauri::command]
+fn clear_whitelist_config(app: tauri::AppHandle) -> Result<(), String> {
     match WHITELIST_CONFIG.lock() {
         Ok(mut whitelist) => {
             *whitelist = WhitelistConfig::new();
+            persist_whitelist_config(&app, &whitelist)?;
             Ok(())
         }
         Err(e) => Err(format!("Failed to clear whitelist config: {}", e))
     }
 }
 
+#[tauri::command]
+async fn load_matching_config(app: tauri::AppHandle) -> Result<bool, String> {
+    use tauri_plugin_dialog::DialogExt;
+
+    let file_path = app
+        .dialog()
+        .file()
+        .add_filter("JSON files", &["json"])
+        .blocking_pick_file();
+
+    match file_path {
+        Some(path) => {
+            let path_str = path.to_string();
+            match fs::read_to_string(&path_str) {
+                Ok(content) => {
+                    match parse_matching_config(&content) {
+                        Ok(config) => {
+                            match MATCHING_CONFIG.lock() {
+                                Ok(mut matching) => {
+                                    *matching = config;
+                                    Ok(true)
+                                }
+                                Err(e) => Err(format!("Failed to update matching config: {}", e))
+                            }
+                        }
+                        Err(e) => Err(format!("Failed to parse matching config: {}", e)),
+                    }
+                }
+                Err(e) => Err(format!("Failed to read file: {}", e)),
+            }
+        }
+        None => Ok(false), // User cancelled
+    }
+}
+
+#[tauri::command]
+fn clear_matching_config() -> Result<(), String> {
+    match MATCHING_CONFIG.lock() {
+        Ok(mut matching) => {
+            *matching = MatchingConfig::new();
+            Ok(())
+        }
+        Err(e) => Err(format!("Failed to clear matching config: {}", e))
+    }
+}
+
+#[tauri::command]
+async fn load_normalization_config(app: tauri::AppHandle) -> Result<bool, String> {
+    use tauri_plugin_dialog::DialogExt;
+
+    let file_path = app
+        .dialog()
+        .file()
+        .add_filter("JSON files", &["json"])
+        .blocking_pick_file();
+
+    match file_path {
+        Some(path) => {
+            let path_str = path.to_string();
+            match fs::read_to_string(&path_str) {
+                Ok(content) => {
+                    match parse_normalization_config(&content) {
+                        Ok(config) => {
+                            match NORMALIZATION_CONFIG.lock() {
+                                Ok(mut normalization) => {
+                                    *normalization = config;
+                                    Ok(true)
+                                }
+                                Err(e) => Err(format!("Failed to update normalization config: {}", e))
+                            }
+                        }
+                        Err(e) => Err(format!("Failed to parse normalization config: {}", e)),
+                    }
+                }
+                Err(e) => Err(format!("Failed to read file: {}", e)),
+            }
+        }
+        None => Ok(false), // User cancelled
+    }
+}
+
+#[tauri::command]
+fn clear_normalization_config() -> Result<(), String> {
+    match NORMALIZATION_CONFIG.lock() {
+        Ok(mut normalization) => {
+            *normalization = NormalizationOptions::default();
+            Ok(())
+        }
+        Err(e) => Err(format!("Failed to clear normalization config: {}", e))
+    }
+}
+
+// Re-derives `normalized_path` for already-loaded requests under the current
+// NORMALIZATION_CONFIG, so changing normalization settings mid-session
+// doesn't require re-picking the HAR file(s).
+#[tauri::command]
+fn renormalize_har_requests(mut requests: Vec<HarRequest>) -> Vec<HarRequest> {
+    let normalization = snapshot_normalization_config();
+    for request in &mut requests {
+        renormalize_request(request, &normalization);
+    }
+    requests
+}
+
 #[tauri::command]
 fn compare_har_requests(req1: HarRequest, req2: HarRequest, keys_only: bool) -> ComparisonResult {
+    // Always lock WHITELIST_CONFIG before MATCHING_CONFIG, matching the
+    // order used by align_har_requests/align_har_requests_vscode - locking
+    // them in different orders across commands is an ABBA deadlock waiting
+    // to happen once the frontend can invoke them concurrently.
     match WHITELIST_CONFIG.lock() {
-        Ok(whitelist) => compare_requests_with_whitelist(&req1, &req2, keys_only, &whitelist),
+        Ok(whitelist) => {
+            let matching = MATCHING_CONFIG.lock().ok();
+            compare_requests_with_whitelist(&req1, &req2, keys_only, &whitelist, matching.as_deref())
+        }
         Err(_) => compare_requests(&req1, &req2, keys_only)
     }
 }
 
 #[tauri::command]
-fn align_har_requests(requests1: Vec<HarRequest>, requests2: Vec<HarRequest>) -> Vec<AlignedPair> {
+fn align_har_requests(app: tauri::AppHandle, requests1: Vec<HarRequest>, requests2: Vec<HarRequest>) -> Vec<AlignedPair> {
+    ALIGN_CANCELLED.store(false, Ordering::SeqCst);
     let whitelist = WHITELIST_CONFIG.lock().ok();
-    align_requests_with_whitelist(&requests1, &requests2, whitelist.as_deref())
+    let matching = MATCHING_CONFIG.lock().ok();
+    let mut on_progress = |done: usize, total: usize| {
+        let _ = app.emit("align_progress", ProgressEvent { done, total });
+    };
+    let should_cancel = || ALIGN_CANCELLED.load(Ordering::SeqCst);
+    align_requests_with_whitelist_with_progress(
+        &requests1,
+        &requests2,
+        whitelist.as_deref(),
+        matching.as_deref(),
+        Some(&mut on_progress),
+        Some(&should_cancel),
+    )
+}
+
+#[tauri::command]
+fn align_har_requests_vscode(app: tauri::AppHandle, requests1: Vec<HarRequest>, requests2: Vec<HarRequest>) -> Vec<AlignedPair> {
+    ALIGN_CANCELLED.store(false, Ordering::SeqCst);
+    let whitelist = WHITELIST_CONFIG.lock().ok();
+    let matching = MATCHING_CONFIG.lock().ok();
+    let mut on_progress = |done: usize, total: usize| {
+        let _ = app.emit("align_progress", ProgressEvent { done, total });
+    };
+    let should_cancel = || ALIGN_CANCELLED.load(Ordering::SeqCst);
+    align_requests_like_vscode_with_whitelist_with_progress(
+        &requests1,
+        &requests2,
+        whitelist.as_deref(),
+        matching.as_deref(),
+        Some(&mut on_progress),
+        Some(&should_cancel),
+    )
+}
+
+#[tauri::command]
+fn cancel_align() {
+    ALIGN_CANCELLED.store(true, Ordering::SeqCst);
+}
+
+// Per-file result of comparing one HAR capture against a shared baseline.
+#[derive(Clone, Serialize)]
+struct BatchComparisonSummary {
+    file_path: String,
+    matched: usize,
+    mismatched: usize,
+    missing: usize,
+    results: Vec<AlignedPair>,
 }
 
 #[tauri::command]
-fn align_har_requests_vscode(requests1: Vec<HarRequest>, requests2: Vec<HarRequest>) -> Vec<AlignedPair> {
+fn compare_har_files_batch(app: tauri::AppHandle, baseline: HarFile, files: Vec<HarFile>) -> Vec<BatchComparisonSummary> {
+    ALIGN_CANCELLED.store(false, Ordering::SeqCst);
     let whitelist = WHITELIST_CONFIG.lock().ok();
-    align_requests_like_vscode_with_whitelist(&requests1, &requests2, whitelist.as_deref())
+    let matching = MATCHING_CONFIG.lock().ok();
+    let file_count = files.len();
+    let should_cancel = || ALIGN_CANCELLED.load(Ordering::SeqCst);
+
+    files
+        .into_iter()
+        .enumerate()
+        .map(|(file_index, file)| {
+            let mut on_progress = |done: usize, total: usize| {
+                let _ = app.emit("batch_align_progress", BatchProgressEvent {
+                    file_path: file.file_path.clone(),
+                    file_index,
+                    file_count,
+                    done,
+                    total,
+                });
+            };
+            let aligned = align_requests_with_whitelist_with_progress(
+                &baseline.requests,
+                &file.requests,
+                whitelist.as_deref(),
+                matching.as_deref(),
+                Some(&mut on_progress),
+                Some(&should_cancel),
+            );
+
+            let mut matched = 0;
+            let mut mismatched = 0;
+            let mut missing = 0;
+
+            for pair in &aligned {
+                match &pair.comparison {
+                    Some(result) if result.status == "match" || result.status == "whitelisted" => matched += 1,
+                    Some(_) => mismatched += 1,
+                    None => missing += 1,
+                }
+            }
+
+            BatchComparisonSummary {
+                file_path: file.file_path,
+                matched,
+                mismatched,
+                missing,
+                results: aligned,
+            }
+        })
+        .collect()
 }
 
 #[tauri::command]
 fn get_detailed_comparison(req1: HarRequest, req2: HarRequest, keys_only: bool) -> DetailedComparison {
+    // Same fixed lock order as compare_har_requests/align_har_requests:
+    // WHITELIST_CONFIG before MATCHING_CONFIG.
     match WHITELIST_CONFIG.lock() {
-        Ok(whitelist) => create_detailed_comparison_with_whitelist(&req1, &req2, keys_only, &whitelist),
-        Err(_) => create_detailed_comparison_with_whitelist(&req1, &req2, keys_only, &WhitelistConfig::new())
+        Ok(whitelist) => {
+            let matching = MATCHING_CONFIG.lock().ok();
+            create_detailed_comparison_with_rules(&req1, &req2, keys_only, &whitelist, matching.as_deref())
+        }
+        Err(_) => {
+            let matching = MATCHING_CONFIG.lock().ok();
+            create_detailed_comparison_with_rules(&req1, &req2, keys_only, &WhitelistConfig::new(), matching.as_deref())
+        }
     }
 }
 
 #[tauri::command]
-fn store_comparison_data(data_id: String, data: String) -> Result<(), String> {
+fn store_comparison_data(app: tauri::AppHandle, data_id: String, data: String) -> Result<(), String> {
     match COMPARISON_DATA_STORE.lock() {
         Ok(mut store) => {
-            store.insert(data_id, data);
-            Ok(())
+            store.insert(data_id.clone(), data.clone());
         }
-        Err(e) => Err(format!("Failed to store comparison data: {}", e))
+        Err(e) => return Err(format!("Failed to store comparison data: {}", e))
     }
+
+    let store_handle = app
+        .store(COMPARISON_STORE_FILE)
+        .map_err(|e| format!("Failed to open comparison data store: {}", e))?;
+    store_handle.set(data_id, serde_json::Value::String(data));
+    store_handle
+        .save()
+        .map_err(|e| format!("Failed to save comparison data store: {}", e))
 }
 
 #[tauri::command]
 fn get_comparison_data(data_id: String) -> Result<Option<String>, String> {
+    match COMPARISON_DATA_STORE.lock() {
+        Ok(store) => Ok(store.get(&data_id).cloned()),
+        Err(e) => Err(format!("Failed to retrieve comparison data: {}", e))
+    }
+}
+
+#[tauri::command]
+fn clear_comparison_data(app: tauri::AppHandle, data_id: String) -> Result<(), String> {
     match COMPARISON_DATA_STORE.lock() {
         Ok(mut store) => {
-            Ok(store.remove(&data_id))
+            store.remove(&data_id);
         }
-        Err(e) => Err(format!("Failed to retrieve comparison data: {}", e))
+        Err(e) => return Err(format!("Failed to clear comparison data: {}", e))
+    }
+
+    if let Ok(store_handle) = app.store(COMPARISON_STORE_FILE) {
+        store_handle.delete(&data_id);
+        let _ = store_handle.save();
+    }
+
+    Ok(())
+}
+
+#[tauri::command]
+async fn export_comparison_report(
+    app: tauri::AppHandle,
+    comparison: Option<DetailedComparison>,
+    data_id: Option<String>,
+    format: String,
+) -> Result<bool, String> {
+    use tauri_plugin_dialog::DialogExt;
+
+    let comparison = match comparison {
+        Some(comparison) => comparison,
+        None => {
+            let data_id = data_id
+                .ok_or_else(|| "Either `comparison` or `data_id` must be provided".to_string())?;
+            let stored = COMPARISON_DATA_STORE
+                .lock()
+                .map_err(|e| format!("Failed to read comparison data store: {}", e))?
+                .get(&data_id)
+                .cloned()
+                .ok_or_else(|| format!("No comparison data found for id '{}'", data_id))?;
+            serde_json::from_str(&stored)
+                .map_err(|e| format!("Failed to parse stored comparison data: {}", e))?
+        }
+    };
+
+    let (content, extension) = render_comparison_report(&comparison, &format)?;
+
+    let file_path = app
+        .dialog()
+        .file()
+        .add_filter(&format, &[extension])
+        .blocking_save_file();
+
+    match file_path {
+        Some(path) => {
+            fs::write(path.to_string(), content).map_err(|e| format!("Failed to write file: {}", e))?;
+            Ok(true)
+        }
+        None => Ok(false), // User cancelled
+    }
+}
+
+#[tauri::command]
+async fn export_alignment_dot(
+    app: tauri::AppHandle,
+    requests1: Vec<HarRequest>,
+    requests2: Vec<HarRequest>,
+    aligned: Vec<AlignedPair>,
+) -> Result<bool, String> {
+    use tauri_plugin_dialog::DialogExt;
+
+    let content = export_alignment_to_dot(&requests1, &requests2, &aligned);
+
+    let file_path = app
+        .dialog()
+        .file()
+        .add_filter("Graphviz DOT", &["dot"])
+        .blocking_save_file();
+
+    match file_path {
+        Some(path) => {
+            fs::write(path.to_string(), content).map_err(|e| format!("Failed to write file: {}", e))?;
+            Ok(true)
+        }
+        None => Ok(false), // User cancelled
     }
 }
 
@@ -141,16 +635,54 @@ pub fn run() {
     tauri::Builder::default()
         .plugin(tauri_plugin_opener::init())
         .plugin(tauri_plugin_dialog::init())
+        .plugin(tauri_plugin_store::Builder::default().build())
+        .plugin(tauri_plugin_http::init())
+        .setup(|app| {
+            if let Ok(store) = app.store(WHITELIST_STORE_FILE) {
+                if let Some(value) = store.get(WHITELIST_STORE_KEY) {
+                    if let Ok(config) = serde_json::from_value::<WhitelistConfig>(value) {
+                        if let Ok(mut whitelist) = WHITELIST_CONFIG.lock() {
+                            *whitelist = config;
+                        }
+                    }
+                }
+            }
+
+            if let Ok(store) = app.store(COMPARISON_STORE_FILE) {
+                if let Ok(mut data_store) = COMPARISON_DATA_STORE.lock() {
+                    for (key, value) in store.entries() {
+                        if let Some(text) = value.as_str() {
+                            data_store.insert(key, text.to_string());
+                        }
+                    }
+                }
+            }
+
+            Ok(())
+        })
         .invoke_handler(tauri::generate_handler![
             open_har_file,
+            open_har_files,
+            fetch_har_from_url,
             load_whitelist_config,
+            save_whitelist_config,
             clear_whitelist_config,
+            load_matching_config,
+            clear_matching_config,
+            load_normalization_config,
+            clear_normalization_config,
+            renormalize_har_requests,
             compare_har_requests,
+            compare_har_files_batch,
             align_har_requests,
             align_har_requests_vscode,
+            cancel_align,
             get_detailed_comparison,
             store_comparison_data,
-            get_comparison_data
+            get_comparison_data,
+            clear_comparison_data,
+            export_comparison_report,
+            export_alignment_dot
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");